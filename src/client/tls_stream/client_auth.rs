@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+/// A client certificate and private key presented to the server to
+/// authenticate the connection via mutual TLS (mTLS).
+///
+/// Mirrors the `Location`/`Bundle` split already used by
+/// [`crate::client::TrustConfig`]: the certificate and key can either be
+/// read from disk when the connection is established, supplied directly
+/// as bytes, or bundled together as a password-protected PKCS#12 archive.
+#[derive(Debug, Clone)]
+pub enum ClientAuth {
+    /// A PEM or DER encoded certificate chain and private key, read from
+    /// the given file paths when the connection is established.
+    CertificateKeyLocation {
+        /// Path to a PEM or DER encoded certificate chain.
+        cert: PathBuf,
+        /// Path to a PEM or DER encoded private key.
+        key: PathBuf,
+    },
+    /// A PEM or DER encoded certificate chain and private key, already
+    /// loaded into memory.
+    CertificateKeyBundle {
+        /// PEM or DER encoded certificate chain.
+        cert: Vec<u8>,
+        /// PEM or DER encoded private key.
+        key: Vec<u8>,
+    },
+    /// A PKCS#12 bundle containing both the certificate chain and the
+    /// private key, protected by `password`.
+    Pkcs12Bundle {
+        /// The raw PKCS#12 (`.pfx`/`.p12`) bytes.
+        pfx: Vec<u8>,
+        /// The password protecting the bundle.
+        password: String,
+    },
+}
+
+/// The contents of a [`ClientAuth`] with any file paths already resolved
+/// to bytes, ready for a backend-specific TLS library to parse.
+pub(crate) enum ClientAuthBytes {
+    CertificateKey { cert: Vec<u8>, key: Vec<u8> },
+    Pkcs12 { pfx: Vec<u8>, password: String },
+}
+
+impl ClientAuth {
+    /// Reads any file paths into memory, returning the raw certificate,
+    /// key or PKCS#12 bytes a TLS backend can parse directly.
+    pub(crate) fn into_bytes(self) -> crate::Result<ClientAuthBytes> {
+        match self {
+            ClientAuth::CertificateKeyLocation { cert, key } => {
+                let cert = std::fs::read(&cert).map_err(|_| {
+                    crate::Error::Tls(format!(
+                        "Could not read client certificate at {}",
+                        cert.to_string_lossy()
+                    ))
+                })?;
+
+                let key = std::fs::read(&key).map_err(|_| {
+                    crate::Error::Tls(format!(
+                        "Could not read client private key at {}",
+                        key.to_string_lossy()
+                    ))
+                })?;
+
+                Ok(ClientAuthBytes::CertificateKey { cert, key })
+            }
+            ClientAuth::CertificateKeyBundle { cert, key } => {
+                Ok(ClientAuthBytes::CertificateKey { cert, key })
+            }
+            ClientAuth::Pkcs12Bundle { pfx, password } => {
+                Ok(ClientAuthBytes::Pkcs12 { pfx, password })
+            }
+        }
+    }
+}
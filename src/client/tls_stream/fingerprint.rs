@@ -0,0 +1,36 @@
+use sha2::{Digest, Sha256};
+
+/// Computes the SHA-256 digest of a DER-encoded certificate, for use with
+/// [`crate::client::TrustConfig::PinnedCertificate`].
+pub(crate) fn sha256_fingerprint(der: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&Sha256::digest(der));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_known_sha256_digest() {
+        // sha256("tiberius") computed independently with `sha256sum`.
+        let expected = [
+            0x99, 0xe4, 0xce, 0x19, 0x02, 0x03, 0x32, 0xce, 0x2c, 0x0b, 0xc0, 0x92, 0x0a, 0x1f,
+            0x00, 0x1f, 0xfe, 0xdb, 0x95, 0x87, 0xe2, 0xc5, 0xd8, 0x09, 0x11, 0xb0, 0x4f, 0x0a,
+            0x47, 0xd1, 0x91, 0xf2,
+        ];
+
+        assert_eq!(sha256_fingerprint(b"tiberius"), expected);
+    }
+
+    #[test]
+    fn same_input_is_deterministic() {
+        assert_eq!(sha256_fingerprint(b"abc"), sha256_fingerprint(b"abc"));
+    }
+
+    #[test]
+    fn different_input_changes_the_digest() {
+        assert_ne!(sha256_fingerprint(b"abc"), sha256_fingerprint(b"abcd"));
+    }
+}
@@ -3,17 +3,74 @@ use crate::{
     error::Error,
 };
 pub(crate) use async_native_tls::TlsStream;
-use async_native_tls::{Certificate, TlsConnector};
+use async_native_tls::{Certificate, Identity, TlsConnector};
 use futures_util::io::{AsyncRead, AsyncWrite};
 use std::fs;
 use tracing::{event, Level};
 
+use super::client_auth::ClientAuthBytes;
+use super::fingerprint::sha256_fingerprint;
 use super::iter_certs::IterCertBundle;
+use super::ssl_key_log::key_log_requested;
+use super::tls_version::{validate_tls_version_range, TlsVersion};
+
+/// native-tls has no TLS 1.3 variant of its own, so a minimum of `Tls13`
+/// can't be pinned as a hard floor like the rustls backend can. Silently
+/// substituting `Tlsv12` would accept exactly the connections a "1.3
+/// only" policy is meant to reject, so this is a hard error instead.
+fn native_min_protocol_version(version: TlsVersion) -> crate::Result<async_native_tls::Protocol> {
+    match version {
+        TlsVersion::Tls12 => Ok(async_native_tls::Protocol::Tlsv12),
+        TlsVersion::Tls13 => Err(Error::Tls(
+            "TLS 1.3 as a minimum version is not enforceable on the native-tls backend"
+                .to_string(),
+        )),
+    }
+}
+
+/// `Tls13` as a maximum means "allow up to and including 1.3", which is
+/// already the native-tls backend's ceiling, so there's nothing to
+/// restrict. `None` tells the caller to leave `max_protocol_version`
+/// unset rather than mistakenly forbidding 1.3.
+fn native_max_protocol_version(version: TlsVersion) -> Option<async_native_tls::Protocol> {
+    match version {
+        TlsVersion::Tls12 => Some(async_native_tls::Protocol::Tlsv12),
+        TlsVersion::Tls13 => None,
+    }
+}
+
+fn client_identity(config: &Config) -> crate::Result<Option<Identity>> {
+    let Some(auth) = config.client_auth.clone() else {
+        return Ok(None);
+    };
+
+    let identity = match auth.into_bytes()? {
+        ClientAuthBytes::CertificateKey { cert, key } => {
+            Identity::from_pkcs8(&cert, &key).map_err(|e| Error::Tls(e.to_string()))?
+        }
+        ClientAuthBytes::Pkcs12 { pfx, password } => {
+            Identity::from_pkcs12(&pfx, &password).map_err(|e| Error::Tls(e.to_string()))?
+        }
+    };
+
+    Ok(Some(identity))
+}
 
 pub(crate) async fn create_tls_stream<S: AsyncRead + AsyncWrite + Unpin + Send>(
     config: &Config,
     stream: S,
 ) -> crate::Result<TlsStream<S>> {
+    validate_tls_version_range(config.min_tls_version, config.max_tls_version)?;
+
+    // Gated the same way as the rustls backend: the env var alone is a
+    // no-op and the config flag alone must not fail connections where
+    // `SSLKEYLOGFILE` was never set.
+    if key_log_requested(config.ssl_key_log, std::env::var_os("SSLKEYLOGFILE").is_some()) {
+        return Err(Error::Tls(
+            "SSL key logging is only supported by the rustls backend".to_string(),
+        ));
+    }
+
     let mut builder = TlsConnector::new();
 
     match &config.trust {
@@ -57,10 +114,101 @@ pub(crate) async fn create_tls_stream<S: AsyncRead + AsyncWrite + Unpin + Send>(
             builder = builder.danger_accept_invalid_hostnames(true);
             builder = builder.use_sni(false);
         }
+        TrustConfig::PinnedCertificate(_) => {
+            event!(
+                Level::WARN,
+                "Trusting the server certificate based on a pinned fingerprint only."
+            );
+
+            // native-tls has no hook to plug in a custom verifier, so we
+            // disable validation here and check the pinned fingerprint
+            // against the negotiated peer certificate below instead.
+            builder = builder.danger_accept_invalid_certs(true);
+            builder = builder.danger_accept_invalid_hostnames(true);
+        }
+        #[cfg(feature = "rustls-webpki-roots")]
+        TrustConfig::WebPkiRoots => {
+            return Err(Error::Tls(
+                "TrustConfig::WebPkiRoots is only supported by the rustls backend".to_string(),
+            ));
+        }
         TrustConfig::Default => {
             event!(Level::INFO, "Using default trust configuration.");
         }
     }
 
-    Ok(builder.connect(config.get_host(), stream).await?)
+    if let Some(identity) = client_identity(config)? {
+        builder = builder.identity(identity);
+    }
+
+    if let Some(min) = config.min_tls_version {
+        builder = builder.min_protocol_version(Some(native_min_protocol_version(min)?));
+    }
+
+    if let Some(max) = config.max_tls_version {
+        if let Some(proto) = native_max_protocol_version(max) {
+            builder = builder.max_protocol_version(Some(proto));
+        }
+    }
+
+    let tls_stream = builder.connect(config.get_host(), stream).await?;
+
+    if let TrustConfig::PinnedCertificate(fingerprints) = &config.trust {
+        let cert = tls_stream
+            .peer_certificate()
+            .map_err(|e| Error::Tls(e.to_string()))?
+            .ok_or_else(|| Error::Tls("Server presented no certificate".to_string()))?;
+
+        let der = cert.to_der().map_err(|e| Error::Tls(e.to_string()))?;
+
+        if !fingerprints.contains(&sha256_fingerprint(&der)) {
+            return Err(Error::Tls(
+                "Server certificate fingerprint does not match any pinned fingerprint"
+                    .to_string(),
+            ));
+        }
+    }
+
+    Ok(tls_stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_tls12_is_supported() {
+        assert!(matches!(
+            native_min_protocol_version(TlsVersion::Tls12),
+            Ok(async_native_tls::Protocol::Tlsv12)
+        ));
+    }
+
+    #[test]
+    fn min_tls13_is_a_hard_error_instead_of_a_silent_downgrade() {
+        assert!(native_min_protocol_version(TlsVersion::Tls13).is_err());
+    }
+
+    #[test]
+    fn max_tls12_forbids_tls13() {
+        assert!(matches!(
+            native_max_protocol_version(TlsVersion::Tls12),
+            Some(async_native_tls::Protocol::Tlsv12)
+        ));
+    }
+
+    #[test]
+    fn max_tls13_does_not_restrict_the_backend_ceiling() {
+        assert!(native_max_protocol_version(TlsVersion::Tls13).is_none());
+    }
+
+    #[test]
+    fn ssl_key_log_flag_without_the_env_var_does_not_reject_the_connection() {
+        assert!(!key_log_requested(true, false));
+    }
+
+    #[test]
+    fn ssl_key_log_flag_with_the_env_var_rejects_the_connection() {
+        assert!(key_log_requested(true, true));
+    }
 }
@@ -0,0 +1,35 @@
+/// Whether SSL key logging should be enabled for this connection.
+///
+/// Requires both the `SSLKEYLOGFILE` environment variable to be set *and*
+/// an explicit `Config` opt-in, so neither one alone can enable key
+/// logging: a stray `SSLKEYLOGFILE` in the environment doesn't silently
+/// turn it on in production, and flipping the config flag without also
+/// setting the env var is a no-op rather than a hard failure.
+pub(crate) fn key_log_requested(ssl_key_log: bool, env_var_present: bool) -> bool {
+    ssl_key_log && env_var_present
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requires_both_the_flag_and_the_env_var() {
+        assert!(key_log_requested(true, true));
+    }
+
+    #[test]
+    fn flag_alone_is_not_enough() {
+        assert!(!key_log_requested(true, false));
+    }
+
+    #[test]
+    fn env_var_alone_is_not_enough() {
+        assert!(!key_log_requested(false, true));
+    }
+
+    #[test]
+    fn neither_set_is_disabled() {
+        assert!(!key_log_requested(false, false));
+    }
+}
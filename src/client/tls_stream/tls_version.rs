@@ -0,0 +1,78 @@
+/// A TLS protocol version to enforce as a floor or ceiling when
+/// negotiating the connection to the server.
+///
+/// Only the rustls backend can enforce `Tls13` as a minimum: the
+/// `native-tls` crate has no protocol variant of its own for TLS 1.3, so
+/// `min_tls_version(Tls13)` is a hard connection error on that backend
+/// rather than a silent fall back to `Tls12`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsVersion {
+    /// TLS 1.2
+    Tls12,
+    /// TLS 1.3
+    Tls13,
+}
+
+impl TlsVersion {
+    fn rank(self) -> u8 {
+        match self {
+            TlsVersion::Tls12 => 0,
+            TlsVersion::Tls13 => 1,
+        }
+    }
+}
+
+/// Rejects a `min_tls_version` that's higher than `max_tls_version`
+/// up front, so the two backends don't each have to resolve the same
+/// contradictory `Config` differently (one picking `min` and ignoring
+/// `max`, the other hard-erroring, or vice versa).
+pub(crate) fn validate_tls_version_range(
+    min: Option<TlsVersion>,
+    max: Option<TlsVersion>,
+) -> crate::Result<()> {
+    if let (Some(min), Some(max)) = (min, max) {
+        if min.rank() > max.rank() {
+            return Err(crate::Error::Tls(format!(
+                "min_tls_version ({min:?}) is greater than max_tls_version ({max:?})"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_greater_than_max_is_rejected() {
+        assert!(
+            validate_tls_version_range(Some(TlsVersion::Tls13), Some(TlsVersion::Tls12)).is_err()
+        );
+    }
+
+    #[test]
+    fn equal_bounds_are_accepted() {
+        assert!(
+            validate_tls_version_range(Some(TlsVersion::Tls12), Some(TlsVersion::Tls12)).is_ok()
+        );
+        assert!(
+            validate_tls_version_range(Some(TlsVersion::Tls13), Some(TlsVersion::Tls13)).is_ok()
+        );
+    }
+
+    #[test]
+    fn min_less_than_max_is_accepted() {
+        assert!(
+            validate_tls_version_range(Some(TlsVersion::Tls12), Some(TlsVersion::Tls13)).is_ok()
+        );
+    }
+
+    #[test]
+    fn a_missing_bound_is_always_accepted() {
+        assert!(validate_tls_version_range(Some(TlsVersion::Tls13), None).is_ok());
+        assert!(validate_tls_version_range(None, Some(TlsVersion::Tls12)).is_ok());
+        assert!(validate_tls_version_range(None, None).is_ok());
+    }
+}